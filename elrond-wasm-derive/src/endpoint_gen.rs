@@ -0,0 +1,149 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{FnArg, ItemFn, Pat, ReturnType, Type};
+
+/// Builds the `#[no_mangle] extern "C"` wrapper for a single endpoint function.
+///
+/// The original function is renamed to `<name>_endpoint_impl` so it doesn't
+/// collide with the generated `extern "C" fn <name>()` wrapper, which keeps
+/// the original, caller-facing name.
+pub(crate) fn expand_endpoint(mut input_fn: ItemFn) -> TokenStream {
+    let fn_name = input_fn.sig.ident.clone();
+    let impl_fn_name = format_ident!("{}_endpoint_impl", fn_name);
+    input_fn.sig.ident = impl_fn_name.clone();
+    let num_args = input_fn.sig.inputs.len() as i32;
+
+    let mut arg_names = Vec::new();
+    let mut arg_loads = Vec::new();
+    for (index, input) in input_fn.sig.inputs.iter().enumerate() {
+        let index = index as i32;
+        let (pat_ident, arg_ty) = match input {
+            FnArg::Typed(pat_type) => (&pat_type.pat, &*pat_type.ty),
+            FnArg::Receiver(_) => panic!("endpoints cannot take `self`"),
+        };
+        let arg_name = match &**pat_ident {
+            Pat::Ident(ident) => ident.ident.clone(),
+            _ => format_ident!("arg_{}", index),
+        };
+        arg_loads.push(load_argument(&arg_name, arg_ty, index));
+        arg_names.push(arg_name);
+    }
+
+    let call_and_finish = match &input_fn.sig.output {
+        ReturnType::Default => quote! {
+            #impl_fn_name(#(#arg_names),*);
+        },
+        ReturnType::Type(_, ty) => finish_result(&impl_fn_name, &arg_names, ty),
+    };
+
+    quote! {
+        #input_fn
+
+        #[no_mangle]
+        pub extern "C" fn #fn_name() {
+            EEI.check_num_arguments(#num_args);
+            #(#arg_loads)*
+            #call_and_finish
+        }
+    }
+}
+
+/// Generates `let <name> = EEI.get_argument_u64(<index>);` for a `u64` argument
+/// and `let <name>: T = EEI.get_argument(<index>);` for any other type `T`.
+fn load_argument(name: &syn::Ident, ty: &Type, index: i32) -> TokenStream {
+    match simple_type_name(ty).as_deref() {
+        Some("u64") => quote! { let #name = EEI.get_argument_u64(#index); },
+        _ => quote! { let #name: #ty = EEI.get_argument(#index); },
+    }
+}
+
+/// Generates the call to the endpoint body plus the matching `finish` call,
+/// routing `Result::Err` to `signal_sc_error` when the return type is a
+/// `Result<T, E>` (requires `E: SCError`).
+fn finish_result(fn_name: &syn::Ident, arg_names: &[syn::Ident], ty: &Type) -> TokenStream {
+    if let Some(ok_ty) = result_ok_type(ty) {
+        let finish_ok = finish_value(quote! { result }, ok_ty);
+        quote! {
+            match #fn_name(#(#arg_names),*) {
+                Ok(result) => { #finish_ok }
+                Err(sc_error) => EEI.signal_sc_error(sc_error),
+            }
+        }
+    } else {
+        let finish = finish_value(quote! { result }, ty);
+        quote! {
+            let result = #fn_name(#(#arg_names),*);
+            #finish
+        }
+    }
+}
+
+fn finish_value(value: TokenStream, ty: &Type) -> TokenStream {
+    match simple_type_name(ty).as_deref() {
+        Some("u64") => quote! { EEI.finish_u64(#value); },
+        _ => quote! { EEI.finish(#value); },
+    }
+}
+
+/// Returns `Some(T)` if `ty` is `Result<T, _>`, `None` otherwise.
+fn result_ok_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    args.args.iter().find_map(|arg| match arg {
+        syn::GenericArgument::Type(t) => Some(t),
+        _ => None,
+    })
+}
+
+fn simple_type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(type_path) => Some(type_path.path.segments.last()?.ident.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn non_primitive_argument_uses_generic_codec_call_and_err_uses_signal_sc_error() {
+        let input_fn: ItemFn = parse_quote! {
+            fn sum_vec(values: Vec<u64>) -> Result<u64, &'static str> {
+                Ok(values.iter().sum())
+            }
+        };
+
+        let expanded = expand_endpoint(input_fn).to_string();
+
+        assert!(expanded.contains("sum_vec_endpoint_impl"));
+        assert!(!expanded.contains("fn sum_vec ("));
+        assert!(expanded.contains("get_argument"));
+        assert!(!expanded.contains("get_argument_u64"));
+        assert!(expanded.contains("signal_sc_error"));
+    }
+
+    #[test]
+    fn u64_argument_and_result_uses_the_fixed_primitive_calls() {
+        let input_fn: ItemFn = parse_quote! {
+            fn echo(x: u64) -> u64 {
+                x
+            }
+        };
+
+        let expanded = expand_endpoint(input_fn).to_string();
+
+        assert!(expanded.contains("get_argument_u64"));
+        assert!(expanded.contains("finish_u64"));
+        assert!(!expanded.contains("signal_sc_error"));
+    }
+}
@@ -0,0 +1,42 @@
+//! Procedural macros that turn plain Rust functions into wasm endpoints.
+//!
+//! Instead of hand-writing the `#[no_mangle] extern "C"` wrapper that loads
+//! each argument off the `EndpointArgumentApi` and pushes the result back
+//! through `EndpointFinishApi`, contract authors write ordinary functions
+//! and annotate them with `#[endpoint]`.
+
+extern crate proc_macro;
+
+mod endpoint_gen;
+
+use proc_macro::TokenStream;
+
+/// Generates the wasm entry point for a smart contract endpoint.
+///
+/// ```ignore
+/// #[endpoint]
+/// fn echo(x: u64) -> u64 {
+///     x
+/// }
+/// ```
+///
+/// expands (roughly) to:
+///
+/// ```ignore
+/// fn echo_endpoint_impl(x: u64) -> u64 {
+///     x
+/// }
+///
+/// #[no_mangle]
+/// pub extern "C" fn echo() {
+///     EEI.check_num_arguments(1);
+///     let x = EEI.get_argument_u64(0);
+///     let result = echo_endpoint_impl(x);
+///     EEI.finish_u64(result);
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn endpoint(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input_fn = syn::parse_macro_input!(item as syn::ItemFn);
+    endpoint_gen::expand_endpoint(input_fn).into()
+}
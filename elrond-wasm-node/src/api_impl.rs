@@ -0,0 +1,60 @@
+use crate::imports;
+use elrond_wasm::api::{EndpointArgumentApi, EndpointFinishApi, ErrorApi};
+
+/// Arguments are read into this buffer before being handed out as a slice.
+/// The VM already enforces a much smaller practical argument size, so this
+/// is generous rather than exact.
+const MAX_ARGUMENT_LEN: usize = 4096;
+static mut ARGUMENT_BUFFER: [u8; MAX_ARGUMENT_LEN] = [0u8; MAX_ARGUMENT_LEN];
+
+/// `EndpointArgumentApi` / `EndpointFinishApi` / `ErrorApi` implementation
+/// backed directly by the Arwen EEI import functions.
+pub struct ArwenApiImpl;
+
+impl EndpointArgumentApi for ArwenApiImpl {
+    fn check_num_arguments(&self, expected: i32) {
+        unsafe {
+            imports::checkNumArguments(expected);
+        }
+    }
+
+    fn get_argument_u64(&self, index: i32) -> u64 {
+        unsafe { imports::getArgumentU64(index) }
+    }
+
+    fn get_argument_bytes(&self, index: i32) -> &[u8] {
+        unsafe {
+            let len = imports::getArgumentLength(index).max(0) as usize;
+            if len > MAX_ARGUMENT_LEN {
+                self.signal_error(&b"argument too large"[..]);
+            }
+            let buffer_ptr = core::ptr::addr_of_mut!(ARGUMENT_BUFFER) as *mut u8;
+            imports::getArgument(index, buffer_ptr);
+            core::slice::from_raw_parts(buffer_ptr, len)
+        }
+    }
+}
+
+impl EndpointFinishApi for ArwenApiImpl {
+    fn finish_u64(&self, value: u64) {
+        unsafe {
+            imports::finishU64(value);
+        }
+    }
+
+    fn finish_bytes(&self, bytes: &[u8]) {
+        unsafe {
+            imports::finish(bytes.as_ptr(), bytes.len() as i32);
+        }
+    }
+}
+
+impl ErrorApi for ArwenApiImpl {
+    fn signal_error(&self, message: &[u8]) -> ! {
+        unsafe { imports::signalError(message.as_ptr(), message.len() as i32) }
+    }
+
+    fn signal_error_with_code(&self, code: u32, message: &[u8]) -> ! {
+        unsafe { imports::signalErrorWithCode(code, message.as_ptr(), message.len() as i32) }
+    }
+}
@@ -0,0 +1,15 @@
+//! Raw Arwen EEI imports. Thin `extern "C"` declarations only — all the
+//! ergonomics live in `api_impl`.
+
+extern "C" {
+    pub fn checkNumArguments(expected: i32);
+    pub fn getArgumentU64(index: i32) -> u64;
+    pub fn getArgumentLength(index: i32) -> i32;
+    pub fn getArgument(index: i32, arg_offset: *mut u8) -> i32;
+
+    pub fn finishU64(value: u64);
+    pub fn finish(data_offset: *const u8, length: i32);
+
+    pub fn signalError(message_offset: *const u8, length: i32) -> !;
+    pub fn signalErrorWithCode(code: u32, message_offset: *const u8, length: i32) -> !;
+}
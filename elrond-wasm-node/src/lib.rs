@@ -0,0 +1,8 @@
+#![no_std]
+#![feature(panic_info_message)]
+
+mod api_impl;
+mod imports;
+mod panic;
+
+pub use api_impl::ArwenApiImpl;
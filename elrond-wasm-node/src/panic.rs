@@ -0,0 +1,74 @@
+use crate::imports;
+use core::fmt::{self, Write};
+use core::panic::PanicInfo;
+use elrond_wasm::StandardErrorCode;
+
+/// How many bytes of panic message we're willing to forward to `signalErrorWithCode`.
+/// Kept small and fixed-size since contracts have no heap to spare for this.
+const PANIC_MESSAGE_CAPACITY: usize = 256;
+
+/// A `core::fmt::Write` sink backed by a fixed-size stack buffer.
+///
+/// Writes past the end of the buffer are silently dropped rather than
+/// causing a second panic while already handling one.
+struct PanicMessageBuffer {
+    bytes: [u8; PANIC_MESSAGE_CAPACITY],
+    len: usize,
+}
+
+impl PanicMessageBuffer {
+    const fn new() -> Self {
+        PanicMessageBuffer {
+            bytes: [0u8; PANIC_MESSAGE_CAPACITY],
+            len: 0,
+        }
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl Write for PanicMessageBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let remaining = PANIC_MESSAGE_CAPACITY - self.len;
+        let to_copy = remaining.min(s.len());
+        self.bytes[self.len..self.len + to_copy].copy_from_slice(&s.as_bytes()[..to_copy]);
+        self.len += to_copy;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "wasm-panic-message")]
+fn render(info: &PanicInfo) -> PanicMessageBuffer {
+    let mut buffer = PanicMessageBuffer::new();
+    // The message is the one piece of information this feature exists to
+    // deliver, so it's written first; `file:line` is only appended if
+    // capacity remains, since it can easily be much longer than the message.
+    let _ = write!(buffer, "{}", info.message());
+    if let Some(location) = info.location() {
+        let _ = write!(buffer, " at {}:{}", location.file(), location.line());
+    }
+    buffer
+}
+
+/// Without the `wasm-panic-message` feature we skip the formatting machinery
+/// entirely to keep release contracts as small as possible.
+#[cfg(not(feature = "wasm-panic-message"))]
+fn render(_info: &PanicInfo) -> PanicMessageBuffer {
+    let mut buffer = PanicMessageBuffer::new();
+    let _ = buffer.write_str("panic occurred");
+    buffer
+}
+
+#[panic_handler]
+fn panic_handler(info: &PanicInfo) -> ! {
+    let buffer = render(info);
+    unsafe {
+        imports::signalErrorWithCode(
+            StandardErrorCode::ExecutionFailed as u32,
+            buffer.as_bytes().as_ptr(),
+            buffer.as_bytes().len() as i32,
+        )
+    }
+}
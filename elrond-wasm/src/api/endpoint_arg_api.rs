@@ -0,0 +1,28 @@
+use super::ErrorApi;
+use crate::io::arg_de;
+use elrond_codec::TopDecode;
+
+/// Gives endpoints access to the raw smart contract call arguments.
+///
+/// Implementations are expected to be thin wrappers around whatever the VM
+/// exposes for reading call data, so this trait intentionally stays close to
+/// the primitives the VM itself understands. The generic `get_argument`
+/// builds on top of `get_argument_bytes` to support any `TopDecode` type.
+pub trait EndpointArgumentApi: ErrorApi + Sized {
+    /// Aborts execution unless the call was made with exactly `expected` arguments.
+    fn check_num_arguments(&self, expected: i32);
+
+    /// Reads argument `index` as a `u64`.
+    fn get_argument_u64(&self, index: i32) -> u64;
+
+    /// Reads argument `index` as the raw bytes passed by the caller.
+    fn get_argument_bytes(&self, index: i32) -> &[u8];
+
+    /// Reads argument `index` and decodes it as `T` via `elrond-codec`.
+    ///
+    /// Calls `signal_error` with a descriptive message instead of returning
+    /// if the bytes don't decode as a valid `T`.
+    fn get_argument<T: TopDecode>(&self, index: i32) -> T {
+        arg_de::load_argument(self, index)
+    }
+}
@@ -0,0 +1,17 @@
+use super::ErrorApi;
+use crate::io::finish_ser;
+use elrond_codec::TopEncode;
+
+/// Gives endpoints a way to return values to the caller.
+pub trait EndpointFinishApi: ErrorApi + Sized {
+    /// Adds a `u64` to the list of results returned by the current call.
+    fn finish_u64(&self, value: u64);
+
+    /// Adds a raw byte slice to the list of results returned by the current call.
+    fn finish_bytes(&self, bytes: &[u8]);
+
+    /// Encodes `value` via `elrond-codec` and adds it to the call results.
+    fn finish<T: TopEncode>(&self, value: T) {
+        finish_ser::finish_value(self, value);
+    }
+}
@@ -0,0 +1,20 @@
+use crate::types::SCError;
+
+/// Gives endpoints a way to abort execution with an error message.
+pub trait ErrorApi {
+    /// Stops execution and reports `message` back to the VM as the call error.
+    ///
+    /// Never returns: the VM tears down the instance as soon as this is called.
+    fn signal_error(&self, message: &[u8]) -> !;
+
+    /// Like `signal_error`, but also reports a numeric status `code`, so
+    /// off-chain clients can branch on well-known codes instead of
+    /// string-matching `message`.
+    fn signal_error_with_code(&self, code: u32, message: &[u8]) -> !;
+
+    /// Convenience for signalling any `SCError`, e.g. the `Err` value of an
+    /// endpoint that returns `Result<T, E>`.
+    fn signal_sc_error<E: SCError>(&self, err: E) -> ! {
+        self.signal_error_with_code(err.status_code(), err.message_bytes())
+    }
+}
@@ -0,0 +1,7 @@
+mod endpoint_arg_api;
+mod endpoint_finish_api;
+mod error_api;
+
+pub use endpoint_arg_api::EndpointArgumentApi;
+pub use endpoint_finish_api::EndpointFinishApi;
+pub use error_api::ErrorApi;
@@ -0,0 +1,77 @@
+use crate::api::EndpointArgumentApi;
+use elrond_codec::TopDecode;
+
+/// Decodes endpoint argument `index` as `T`, using whatever bytes
+/// `get_argument_bytes` returns for it.
+///
+/// On a decode error the call is aborted via `signal_error` with the
+/// decoder's own error message, so callers get a readable reason instead of
+/// a generic trap.
+pub fn load_argument<A: EndpointArgumentApi, T: TopDecode>(api: &A, index: i32) -> T {
+    let bytes = api.get_argument_bytes(index);
+    match T::top_decode(bytes) {
+        Ok(value) => value,
+        Err(de_err) => api.signal_error(de_err.message_bytes()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::ErrorApi;
+    use elrond_codec::DecodeError;
+    use std::{panic, string::String};
+
+    /// A `TopDecode` type that always fails, so we can observe what
+    /// `load_argument` does on the error path.
+    struct AlwaysFailsToDecode;
+
+    impl TopDecode for AlwaysFailsToDecode {
+        fn top_decode(_bytes: &[u8]) -> Result<Self, DecodeError> {
+            Err(DecodeError::from("always fails"))
+        }
+    }
+
+    struct MockApi;
+
+    impl EndpointArgumentApi for MockApi {
+        fn check_num_arguments(&self, _expected: i32) {}
+
+        fn get_argument_u64(&self, _index: i32) -> u64 {
+            0
+        }
+
+        fn get_argument_bytes(&self, _index: i32) -> &[u8] {
+            &[]
+        }
+    }
+
+    impl ErrorApi for MockApi {
+        fn signal_error(&self, message: &[u8]) -> ! {
+            panic!(
+                "signal_error: {}",
+                core::str::from_utf8(message).unwrap_or("<invalid utf8>")
+            );
+        }
+
+        fn signal_error_with_code(&self, _code: u32, message: &[u8]) -> ! {
+            self.signal_error(message)
+        }
+    }
+
+    #[test]
+    fn decode_failure_reaches_signal_error() {
+        let api = MockApi;
+        let outcome = panic::catch_unwind(|| {
+            let _: AlwaysFailsToDecode = load_argument(&api, 0);
+        });
+
+        let payload = outcome.expect_err("decode failure should abort via signal_error");
+        let message = payload.downcast_ref::<String>().cloned().unwrap_or_default();
+        assert!(
+            message.starts_with("signal_error:"),
+            "expected the signal_error path, got: {message}"
+        );
+        assert!(message.contains("always fails"));
+    }
+}
@@ -0,0 +1,19 @@
+use crate::api::EndpointFinishApi;
+use crate::types::StandardErrorCode;
+use elrond_codec::TopEncode;
+
+/// Encodes `value` via `elrond-codec` and streams the resulting bytes into
+/// the VM output buffer through `finish_bytes`.
+///
+/// On an encode error the call is aborted via `signal_error_with_code`,
+/// mirroring how `load_argument` handles decode errors, rather than panicking.
+pub fn finish_value<A: EndpointFinishApi, T: TopEncode>(api: &A, value: T) {
+    let mut buffer = elrond_codec::Vec::new();
+    if let Err(en_err) = value.top_encode(&mut buffer) {
+        api.signal_error_with_code(
+            StandardErrorCode::ExecutionFailed as u32,
+            en_err.message_bytes(),
+        );
+    }
+    api.finish_bytes(&buffer);
+}
@@ -0,0 +1,2 @@
+pub mod arg_de;
+pub mod finish_ser;
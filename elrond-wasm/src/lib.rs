@@ -0,0 +1,11 @@
+#![no_std]
+
+#[cfg(test)]
+extern crate std;
+
+pub mod api;
+pub mod io;
+pub mod types;
+
+pub use api::{EndpointArgumentApi, EndpointFinishApi, ErrorApi};
+pub use types::{SCError, StandardErrorCode};
@@ -0,0 +1,3 @@
+mod sc_error;
+
+pub use sc_error::{SCError, StandardErrorCode};
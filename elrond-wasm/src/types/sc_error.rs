@@ -0,0 +1,40 @@
+/// Well-known status codes, returned to the VM alongside the error message
+/// so off-chain clients can branch on the failure kind instead of
+/// string-matching the message.
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StandardErrorCode {
+    UserError = 4,
+    OutOfFunds = 5,
+    ExecutionFailed = 10,
+}
+
+/// Anything an endpoint can return as `Err` and have turned into a VM-level
+/// error: a numeric status code plus an optional message.
+///
+/// The endpoint macro calls `signal_error_with_code` with these two pieces
+/// whenever an endpoint returns `Result<T, E>` and `E: SCError`.
+pub trait SCError {
+    fn status_code(&self) -> u32;
+    fn message_bytes(&self) -> &[u8];
+}
+
+impl SCError for &str {
+    fn status_code(&self) -> u32 {
+        StandardErrorCode::UserError as u32
+    }
+
+    fn message_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl SCError for StandardErrorCode {
+    fn status_code(&self) -> u32 {
+        *self as u32
+    }
+
+    fn message_bytes(&self) -> &[u8] {
+        &[]
+    }
+}
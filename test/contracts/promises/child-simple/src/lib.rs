@@ -4,6 +4,8 @@
 #![feature(lang_items)]
 
 use elrond_wasm::api::{EndpointArgumentApi, EndpointFinishApi, ErrorApi};
+use elrond_wasm::StandardErrorCode;
+use elrond_wasm_derive::endpoint;
 use elrond_wasm_node::ArwenApiImpl;
 
 pub static EEI: ArwenApiImpl = ArwenApiImpl{};
@@ -19,16 +21,37 @@ pub extern "C" fn answer_wrong() {
 }
 
 // receives u64 as argument and returns it back
-#[no_mangle]
-pub extern "C" fn echo() {
-    EEI.check_num_arguments(1);
+#[endpoint]
+fn echo(x: u64) -> u64 {
+    x
+}
 
-    let arg = EEI.get_argument_u64(0);
+// receives a list of u64 and returns their sum, exercising the generic
+// get_argument codec path for the argument; the u64 sum is still returned
+// through finish_u64
+#[endpoint]
+fn sum_vec(values: elrond_codec::Vec<u64>) -> u64 {
+    values.iter().sum()
+}
 
-    EEI.finish_u64(arg);
+// returns Err for odd arguments, exercising the signal_sc_error wiring
+#[endpoint]
+fn checked_half(x: u64) -> Result<u64, &'static str> {
+    if x % 2 == 0 {
+        Ok(x / 2)
+    } else {
+        Err("checked_half: argument must be even")
+    }
 }
 
 #[no_mangle]
 pub extern "C" fn fail() {
-    EEI.signal_error(&b"fail"[..]);
+    EEI.signal_error_with_code(StandardErrorCode::UserError as u32, &b"fail"[..]);
+}
+
+// divides by zero on purpose, so the panic reaches the VM via the
+// #[panic_handler] in elrond-wasm-node instead of trapping
+#[endpoint]
+fn divide(a: u64, b: u64) -> u64 {
+    a / b
 }